@@ -0,0 +1,245 @@
+//! Minimal DER/PEM building blocks for wrapping raw FIPS-encoded keys in
+//! PKCS#8 (private key) and SubjectPublicKeyInfo (public key) framing, per
+//! [RFC 5280 §4.1] and [RFC 5958].
+//!
+//! This only implements the narrow subset of ASN.1 needed to wrap an
+//! `AlgorithmIdentifier { algorithm OID, parameters ABSENT }` around an
+//! opaque key blob, which is all ML-DSA and ML-KEM need.
+//!
+//! [RFC 5280 §4.1]: https://www.rfc-editor.org/rfc/rfc5280#section-4.1
+//! [RFC 5958]: https://www.rfc-editor.org/rfc/rfc5958
+
+const TAG_INTEGER: u8 = 0x02;
+const TAG_BIT_STRING: u8 = 0x03;
+const TAG_OCTET_STRING: u8 = 0x04;
+const TAG_OID: u8 = 0x06;
+const TAG_SEQUENCE: u8 = 0x30;
+
+/// DER-encoded OID for ML-DSA-87, `2.16.840.1.101.3.4.3.19`.
+pub const ML_DSA_87_OID: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x03, 0x13];
+
+/// DER-encoded OID for ML-KEM-1024, `2.16.840.1.101.3.4.4.3`.
+pub const ML_KEM_1024_OID: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x04, 0x03];
+
+fn encode_len(len: usize) -> Vec<u8> {
+    if len < 0x80 {
+        return vec![len as u8];
+    }
+
+    let mut bytes = len.to_be_bytes().to_vec();
+    while bytes.first() == Some(&0) {
+        bytes.remove(0);
+    }
+
+    let mut out = vec![0x80 | bytes.len() as u8];
+    out.extend(bytes);
+    out
+}
+
+fn tlv(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut out = vec![tag];
+    out.extend(encode_len(content.len()));
+    out.extend(content);
+    out
+}
+
+/// Reads a single DER TLV at `offset`, returning `(tag, content, offset_after)`.
+fn read_tlv(bytes: &[u8], offset: usize) -> Result<(u8, &[u8], usize), String> {
+    let tag = *bytes.get(offset).ok_or("Unexpected end of DER input while reading tag")?;
+    let first_len_byte = *bytes.get(offset + 1).ok_or("Unexpected end of DER input while reading length")?;
+
+    let (len, content_start) = if first_len_byte < 0x80 {
+        (first_len_byte as usize, offset + 2)
+    } else {
+        let num_len_bytes = (first_len_byte & 0x7f) as usize;
+        if num_len_bytes > std::mem::size_of::<usize>() {
+            return Err("DER length field is too wide to fit in a usize".into());
+        }
+        let len_bytes = bytes
+            .get(offset + 2..offset + 2 + num_len_bytes)
+            .ok_or("Unexpected end of DER input while reading long-form length")?;
+        let mut len: usize = 0;
+        for b in len_bytes {
+            len = (len << 8) | *b as usize;
+        }
+        (len, offset + 2 + num_len_bytes)
+    };
+
+    let content_end = content_start.checked_add(len).ok_or("DER length field overflows while computing content end")?;
+    let content = bytes.get(content_start..content_end).ok_or("DER length field overruns available input")?;
+
+    Ok((tag, content, content_start + len))
+}
+
+fn algorithm_identifier(oid: &[u8]) -> Vec<u8> {
+    tlv(TAG_SEQUENCE, &tlv(TAG_OID, oid))
+}
+
+/// Reads an `AlgorithmIdentifier` SEQUENCE at `offset` and checks that its OID matches `expected_oid`,
+/// returning the offset just past the `AlgorithmIdentifier`.
+fn read_and_check_algorithm_identifier(bytes: &[u8], offset: usize, expected_oid: &[u8]) -> Result<usize, String> {
+    let (algo_tag, algo_content, algo_end) = read_tlv(bytes, offset)?;
+    if algo_tag != TAG_SEQUENCE {
+        return Err("Expected an AlgorithmIdentifier SEQUENCE".into());
+    }
+
+    let (oid_tag, oid, _) = read_tlv(algo_content, 0)?;
+    if oid_tag != TAG_OID {
+        return Err("Expected an OID in AlgorithmIdentifier".into());
+    }
+
+    if oid != expected_oid {
+        return Err("AlgorithmIdentifier OID does not match the expected key type".into());
+    }
+
+    Ok(algo_end)
+}
+
+/// Wraps a raw public key in a `SubjectPublicKeyInfo` DER structure:
+/// `SEQUENCE { AlgorithmIdentifier, BIT STRING key }`.
+pub fn wrap_spki(oid: &[u8], public_key: &[u8]) -> Vec<u8> {
+    let mut bit_string_content = vec![0u8];
+    bit_string_content.extend(public_key);
+
+    let mut content = algorithm_identifier(oid);
+    content.extend(tlv(TAG_BIT_STRING, &bit_string_content));
+
+    tlv(TAG_SEQUENCE, &content)
+}
+
+/// Unwraps a `SubjectPublicKeyInfo` DER structure, checking that its `AlgorithmIdentifier` carries
+/// `expected_oid`, and returns the raw public key bytes.
+pub fn unwrap_spki(expected_oid: &[u8], der: &[u8]) -> Result<Vec<u8>, String> {
+    let (tag, outer, _) = read_tlv(der, 0)?;
+    if tag != TAG_SEQUENCE {
+        return Err("Expected a SEQUENCE at the start of SubjectPublicKeyInfo".into());
+    }
+
+    let algo_end = read_and_check_algorithm_identifier(outer, 0, expected_oid)?;
+
+    let (bit_string_tag, bit_string, _) = read_tlv(outer, algo_end)?;
+    if bit_string_tag != TAG_BIT_STRING {
+        return Err("Expected a BIT STRING holding the public key in SubjectPublicKeyInfo".into());
+    }
+
+    Ok(bit_string.get(1..).ok_or("BIT STRING is missing its unused-bits byte")?.to_vec())
+}
+
+/// Wraps a raw private key in a PKCS#8 `OneAsymmetricKey` DER structure:
+/// `SEQUENCE { INTEGER version, AlgorithmIdentifier, OCTET STRING key }`.
+pub fn wrap_pkcs8(oid: &[u8], private_key: &[u8]) -> Vec<u8> {
+    let version = tlv(TAG_INTEGER, &[0x00]);
+    let mut content = version;
+    content.extend(algorithm_identifier(oid));
+    content.extend(tlv(TAG_OCTET_STRING, private_key));
+
+    tlv(TAG_SEQUENCE, &content)
+}
+
+/// Unwraps a PKCS#8 `OneAsymmetricKey` DER structure, checking that its `AlgorithmIdentifier` carries
+/// `expected_oid`, and returns the raw private key bytes.
+pub fn unwrap_pkcs8(expected_oid: &[u8], der: &[u8]) -> Result<Vec<u8>, String> {
+    let (tag, outer, _) = read_tlv(der, 0)?;
+    if tag != TAG_SEQUENCE {
+        return Err("Expected a SEQUENCE at the start of PKCS#8 OneAsymmetricKey".into());
+    }
+
+    let (version_tag, _, version_end) = read_tlv(outer, 0)?;
+    if version_tag != TAG_INTEGER {
+        return Err("Expected an INTEGER version in PKCS#8 OneAsymmetricKey".into());
+    }
+
+    let algo_end = read_and_check_algorithm_identifier(outer, version_end, expected_oid)?;
+
+    let (octet_string_tag, octet_string, _) = read_tlv(outer, algo_end)?;
+    if octet_string_tag != TAG_OCTET_STRING {
+        return Err("Expected an OCTET STRING holding the private key in PKCS#8 OneAsymmetricKey".into());
+    }
+
+    Ok(octet_string.to_vec())
+}
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64_encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for chunk in data.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = *chunk.get(1).unwrap_or(&0);
+        let b2 = *chunk.get(2).unwrap_or(&0);
+
+        out.push(BASE64_ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(BASE64_ALPHABET[(((b0 & 0x03) << 4) | (b1 >> 4)) as usize] as char);
+        out.push(if chunk.len() > 1 {
+            BASE64_ALPHABET[(((b1 & 0x0f) << 2) | (b2 >> 6)) as usize] as char
+        } else {
+            '='
+        });
+        out.push(if chunk.len() > 2 { BASE64_ALPHABET[(b2 & 0x3f) as usize] as char } else { '=' });
+    }
+
+    out
+}
+
+fn base64_decode(data: &str) -> Result<Vec<u8>, String> {
+    fn value(c: u8) -> Result<u8, String> {
+        BASE64_ALPHABET
+            .iter()
+            .position(|&b| b == c)
+            .map(|p| p as u8)
+            .ok_or_else(|| format!("Invalid base64 character: {}", c as char))
+    }
+
+    let cleaned: Vec<u8> = data.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+    let mut out = Vec::with_capacity(cleaned.len() / 4 * 3);
+
+    for chunk in cleaned.chunks(4) {
+        if chunk.len() != 4 {
+            return Err("Base64 input length is not a multiple of 4".into());
+        }
+
+        let pad = chunk.iter().filter(|&&b| b == b'=').count();
+        let v0 = value(chunk[0])?;
+        let v1 = value(chunk[1])?;
+        let v2 = if chunk[2] == b'=' { 0 } else { value(chunk[2])? };
+        let v3 = if chunk[3] == b'=' { 0 } else { value(chunk[3])? };
+
+        out.push((v0 << 2) | (v1 >> 4));
+        if pad < 2 {
+            out.push((v1 << 4) | (v2 >> 2));
+        }
+        if pad < 1 {
+            out.push((v2 << 6) | v3);
+        }
+    }
+
+    Ok(out)
+}
+
+/// Wraps DER bytes in a PEM envelope with the given label, e.g. `"PUBLIC KEY"` or `"PRIVATE KEY"`.
+pub fn der_to_pem(der: &[u8], label: &str) -> String {
+    let body = base64_encode(der);
+    let mut out = format!("-----BEGIN {label}-----\n");
+    for line in body.as_bytes().chunks(64) {
+        out.push_str(std::str::from_utf8(line).expect("base64 output is ASCII"));
+        out.push('\n');
+    }
+    out.push_str(&format!("-----END {label}-----\n"));
+    out
+}
+
+/// Strips a PEM envelope with the given label and returns the decoded DER bytes.
+pub fn pem_to_der(pem: &str, label: &str) -> Result<Vec<u8>, String> {
+    let begin = format!("-----BEGIN {label}-----");
+    let end = format!("-----END {label}-----");
+
+    let body_start = pem.find(&begin).ok_or_else(|| format!("Missing '{begin}' header"))? + begin.len();
+    let body_end = pem.find(&end).ok_or_else(|| format!("Missing '{end}' footer"))?;
+
+    if body_end < body_start {
+        return Err("PEM footer appears before header".into());
+    }
+
+    base64_decode(&pem[body_start..body_end])
+}
@@ -0,0 +1,154 @@
+use ed25519_dalek::{Signer, Verifier};
+use ml_dsa::{EncodedSignature, EncodedSigningKey, EncodedVerifyingKey, KeyGen, MlDsa87, Signature, SigningKey, VerifyingKey};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct HybridSigKeypair {
+    public_key: Vec<u8>,
+    private_key: Vec<u8>,
+}
+
+#[wasm_bindgen]
+pub struct HybridSig;
+
+#[wasm_bindgen]
+impl HybridSigKeypair {
+    #[wasm_bindgen(getter, js_name = "publicKey")]
+    pub fn public_key(&self) -> Vec<u8> {
+        self.public_key.to_owned()
+    }
+
+    #[wasm_bindgen(getter, js_name = "privateKey")]
+    pub fn private_key(&self) -> Vec<u8> {
+        self.private_key.to_owned()
+    }
+}
+
+/// Concatenates `a` and `b` behind a 2-byte big-endian length prefix for `a`, so the two can later
+/// be split apart unambiguously: `len(a) || a || b`.
+fn length_prefixed(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut out = (a.len() as u16).to_be_bytes().to_vec();
+    out.extend_from_slice(a);
+    out.extend_from_slice(b);
+    out
+}
+
+/// Splits bytes laid out as `len(a) || a || b` back into `(a, b)`.
+fn split_length_prefixed(bytes: &[u8]) -> Result<(&[u8], &[u8]), String> {
+    let len_bytes: [u8; 2] = bytes.get(0..2).ok_or("Input is too short to contain a length prefix")?.try_into().unwrap();
+    let len = u16::from_be_bytes(len_bytes) as usize;
+
+    let a = bytes.get(2..2 + len).ok_or("Length prefix overruns available input")?;
+    let b = bytes.get(2 + len..).ok_or("Input is too short to contain the second component")?;
+
+    Ok((a, b))
+}
+
+/// Binds `context` into the bytes handed to Ed25519, which has no native notion of a context
+/// string: `len(context) || context || message`. ML-DSA's own `context` parameter already does
+/// this for the post-quantum side, so both signing inputs end up bound to the same context.
+fn ed25519_message_with_context(message: &[u8], context: &[u8]) -> Vec<u8> {
+    let mut out = vec![context.len() as u8];
+    out.extend_from_slice(context);
+    out.extend_from_slice(message);
+    out
+}
+
+#[wasm_bindgen]
+impl HybridSig {
+    /// Generates a composite keypair made up of an ML-DSA-87 keypair and an Ed25519 keypair,
+    /// for migration safety: the composite signature stays unforgeable as long as *either*
+    /// scheme remains secure.
+    ///
+    /// The composite public key is laid out as `len(mldsa_pk) || mldsa_pk || ed25519_pk`, and the
+    /// composite private key as `len(mldsa_sk) || mldsa_sk || ed25519_sk`.
+    #[wasm_bindgen(js_name = "KeyGen")]
+    pub fn keygen() -> HybridSigKeypair {
+        let mut rng = rand::rngs::OsRng;
+
+        let mldsa_keypair = MlDsa87::key_gen(&mut rng);
+        let ed25519_signing_key = ed25519_dalek::SigningKey::generate(&mut rng);
+
+        let public_key = length_prefixed(&mldsa_keypair.verifying_key().encode(), ed25519_signing_key.verifying_key().as_bytes());
+        let private_key = length_prefixed(&mldsa_keypair.signing_key().encode(), ed25519_signing_key.to_bytes().as_slice());
+
+        HybridSigKeypair { public_key, private_key }
+    }
+
+    /// Signs `message` under both the ML-DSA-87 and Ed25519 components of `privateKey`, binding
+    /// `context` identically into both signing inputs, and concatenates the two signatures as
+    /// `len(mldsa_sig) || mldsa_sig || ed25519_sig`.
+    ///
+    /// ### Parameters
+    /// * `private_key`: Composite private key generated with `KeyGen`
+    /// * `message`: Message that is to be signed
+    /// * `context`: Optional context string (up to 255 bytes), treated as an empty string if omitted
+    #[wasm_bindgen(js_name = "Sign")]
+    pub fn sign(#[wasm_bindgen(js_name = "privateKey")] private_key: &[u8], message: &[u8], context: Option<Vec<u8>>) -> Result<Vec<u8>, String> {
+        let mut rng = rand::rngs::OsRng;
+        let context = context.unwrap_or_default();
+
+        if context.len() > 255 {
+            return Err("Context is expected to be at most 255 bytes".into());
+        }
+
+        let (mldsa_sk, ed25519_sk) = split_length_prefixed(private_key)?;
+
+        let encoded_mldsa_sk = EncodedSigningKey::<MlDsa87>::try_from(mldsa_sk).map_err(|e| format!("Could not get encoded ML-DSA signing key: {e}"))?;
+        let mldsa_signature = SigningKey::<MlDsa87>::decode(&encoded_mldsa_sk)
+            .sign_randomized(message, &context, &mut rng)
+            .unwrap()
+            .encode();
+
+        let ed25519_sk: [u8; 32] = ed25519_sk.try_into().map_err(|_| "Ed25519 signing key is expected to be exactly 32 bytes")?;
+        let ed25519_signing_key = ed25519_dalek::SigningKey::from_bytes(&ed25519_sk);
+        let ed25519_signature = ed25519_signing_key.sign(&ed25519_message_with_context(message, &context));
+
+        Ok(length_prefixed(&mldsa_signature, &ed25519_signature.to_bytes()))
+    }
+
+    /// Verifies a composite signature produced by `Sign`. Returns `true` only if *both* the
+    /// ML-DSA-87 and the Ed25519 signature verify over the same message and context.
+    ///
+    /// ### Parameters
+    /// * `public_key`: Composite public key generated with `KeyGen`
+    /// * `message`: Signed message
+    /// * `signature`: The composite signature that is to be verified
+    /// * `context`: Optional context string (up to 255 bytes), treated as an empty string if omitted
+    #[wasm_bindgen(js_name = "Verify")]
+    pub fn verify(
+        #[wasm_bindgen(js_name = "publicKey")] public_key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+        context: Option<Vec<u8>>,
+    ) -> Result<bool, String> {
+        let context = context.unwrap_or_default();
+
+        if context.len() > 255 {
+            return Err("Context is expected to be at most 255 bytes".into());
+        }
+
+        let (mldsa_pk, ed25519_pk) = split_length_prefixed(public_key)?;
+        let (mldsa_sig, ed25519_sig) = split_length_prefixed(signature)?;
+
+        let encoded_mldsa_pk = EncodedVerifyingKey::<MlDsa87>::try_from(mldsa_pk).map_err(|e| format!("Could not get encoded ML-DSA verifying key: {e}"))?;
+        let mldsa_verifying_key = VerifyingKey::<MlDsa87>::decode(&encoded_mldsa_pk);
+        let encoded_mldsa_sig = EncodedSignature::<MlDsa87>::try_from(mldsa_sig).map_err(|e| format!("Could not get encoded ML-DSA signature: {e}"))?;
+
+        let mldsa_valid = match Signature::<MlDsa87>::decode(&encoded_mldsa_sig) {
+            Some(sigma) => mldsa_verifying_key.verify_with_context(message, &context, &sigma),
+            None => return Err("Could not decode ML-DSA signature".to_string()),
+        };
+
+        let ed25519_pk: [u8; 32] = ed25519_pk.try_into().map_err(|_| "Ed25519 verifying key is expected to be exactly 32 bytes")?;
+        let ed25519_verifying_key = ed25519_dalek::VerifyingKey::from_bytes(&ed25519_pk).map_err(|e| format!("Could not decode Ed25519 verifying key: {e}"))?;
+        let ed25519_sig: [u8; 64] = ed25519_sig.try_into().map_err(|_| "Ed25519 signature is expected to be exactly 64 bytes")?;
+        let ed25519_signature = ed25519_dalek::Signature::from_bytes(&ed25519_sig);
+
+        let ed25519_valid = ed25519_verifying_key
+            .verify(&ed25519_message_with_context(message, &context), &ed25519_signature)
+            .is_ok();
+
+        Ok(mldsa_valid && ed25519_valid)
+    }
+}
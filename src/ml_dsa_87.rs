@@ -3,11 +3,14 @@ use ml_dsa::{
     SigningKey, VerifyingKey, signature::SignerMut,
 };
 use wasm_bindgen::prelude::*;
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::der::{self, ML_DSA_87_OID};
 
 #[wasm_bindgen]
 pub struct MlDsa {
-    verifying_key: VerifyingKey<MlDsa87>,
-    signing_key: SigningKey<MlDsa87>,
+    verifying_key: Vec<u8>,
+    signing_key: Zeroizing<Vec<u8>>,
 }
 
 #[wasm_bindgen]
@@ -17,19 +20,19 @@ impl MlDsa {
         let mut rng = rand::thread_rng();
         let keypair = MlDsa87::key_gen(&mut rng);
         Self {
-            verifying_key: keypair.verifying_key().clone(),
-            signing_key: keypair.signing_key().clone(),
+            verifying_key: keypair.verifying_key().encode().to_vec(),
+            signing_key: Zeroizing::new(keypair.signing_key().encode().to_vec()),
         }
     }
 
     #[wasm_bindgen(getter, js_name = "verifyingKey")]
     pub fn verifying_key(&self) -> Vec<u8> {
-        self.verifying_key.encode().to_vec()
+        self.verifying_key.clone()
     }
 
     #[wasm_bindgen(getter, js_name = "signingKey")]
     pub fn signing_key(&self) -> Vec<u8> {
-        self.signing_key.encode().to_vec()
+        self.signing_key.to_vec()
     }
 
     #[wasm_bindgen]
@@ -43,11 +46,60 @@ impl MlDsa {
         let signing_key = SigningKey::<MlDsa87>::decode(&encoded_signing_key);
 
         Ok(Self {
-            verifying_key,
-            signing_key,
+            verifying_key: verifying_key.encode().to_vec(),
+            signing_key: Zeroizing::new(signing_key.encode().to_vec()),
         })
     }
 
+    /// Wipes the signing key, and the verifying key for consistency, from memory. `MlDsa` tends to
+    /// be held onto across multiple `sign` calls, so callers should call this explicitly once done
+    /// rather than rely on it being dropped promptly.
+    #[wasm_bindgen]
+    pub fn zeroize(&mut self) {
+        self.signing_key.zeroize();
+        self.verifying_key.zeroize();
+    }
+
+    #[wasm_bindgen(js_name = "toSpkiDer")]
+    pub fn to_spki_der(&self) -> Vec<u8> {
+        der::wrap_spki(ML_DSA_87_OID, &self.verifying_key())
+    }
+
+    #[wasm_bindgen(js_name = "toSpkiPem")]
+    pub fn to_spki_pem(&self) -> String {
+        der::der_to_pem(&self.to_spki_der(), "PUBLIC KEY")
+    }
+
+    #[wasm_bindgen(js_name = "toPkcs8Der")]
+    pub fn to_pkcs8_der(&self) -> Vec<u8> {
+        der::wrap_pkcs8(ML_DSA_87_OID, &self.signing_key())
+    }
+
+    #[wasm_bindgen(js_name = "toPkcs8Pem")]
+    pub fn to_pkcs8_pem(&self) -> String {
+        der::der_to_pem(&self.to_pkcs8_der(), "PRIVATE KEY")
+    }
+
+    #[wasm_bindgen(js_name = "fromSpkiDer")]
+    pub fn from_spki_der(der: &[u8]) -> Result<Vec<u8>, String> {
+        der::unwrap_spki(ML_DSA_87_OID, der)
+    }
+
+    #[wasm_bindgen(js_name = "fromSpkiPem")]
+    pub fn from_spki_pem(pem: &str) -> Result<Vec<u8>, String> {
+        der::unwrap_spki(ML_DSA_87_OID, &der::pem_to_der(pem, "PUBLIC KEY")?)
+    }
+
+    #[wasm_bindgen(js_name = "fromPkcs8Der")]
+    pub fn from_pkcs8_der(der: &[u8]) -> Result<Vec<u8>, String> {
+        der::unwrap_pkcs8(ML_DSA_87_OID, der)
+    }
+
+    #[wasm_bindgen(js_name = "fromPkcs8Pem")]
+    pub fn from_pkcs8_pem(pem: &str) -> Result<Vec<u8>, String> {
+        der::unwrap_pkcs8(ML_DSA_87_OID, &der::pem_to_der(pem, "PRIVATE KEY")?)
+    }
+
     #[wasm_bindgen]
     pub fn sign(signing_key: &[u8], msg: &[u8]) -> Result<Vec<u8>, String> {
         let encoded_signing_key = EncodedSigningKey::<MlDsa87>::try_from(signing_key)
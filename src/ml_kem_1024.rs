@@ -1,13 +1,16 @@
 use ml_kem::{
     Ciphertext, Encoded, EncodedSizeUser, KemCore, MlKem1024, MlKem1024Params,
-    kem::{Decapsulate, DecapsulationKey, Encapsulate, EncapsulationKey, Kem},
+    kem::{Decapsulate, DecapsulationKey, Encapsulate, EncapsulateDeterministic, EncapsulationKey, Kem},
 };
 use wasm_bindgen::prelude::*;
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::der::{self, ML_KEM_1024_OID};
 
 #[wasm_bindgen]
 pub struct MlKem {
-    decapsulation_key: DecapsulationKey<MlKem1024Params>,
-    encapsulation_key: EncapsulationKey<MlKem1024Params>,
+    decapsulation_key: Zeroizing<Vec<u8>>,
+    encapsulation_key: Vec<u8>,
 }
 
 #[wasm_bindgen]
@@ -36,8 +39,8 @@ impl MlKem {
         let mut rng = rand::thread_rng();
         let (decapsulation_key, encapsulation_key) = MlKem1024::generate(&mut rng);
         Self {
-            decapsulation_key,
-            encapsulation_key,
+            decapsulation_key: Zeroizing::new(decapsulation_key.as_bytes().to_vec()),
+            encapsulation_key: encapsulation_key.as_bytes().to_vec(),
         }
     }
 
@@ -56,19 +59,68 @@ impl MlKem {
         let decapsulation_key = DecapsulationKey::<MlKem1024Params>::from_bytes(&encoded_dec_key);
 
         Ok(Self {
-            encapsulation_key,
-            decapsulation_key,
+            encapsulation_key: encapsulation_key.as_bytes().to_vec(),
+            decapsulation_key: Zeroizing::new(decapsulation_key.as_bytes().to_vec()),
         })
     }
 
     #[wasm_bindgen(getter, js_name = "encapsulationKey")]
     pub fn encapsulation_key(&self) -> Vec<u8> {
-        self.encapsulation_key.as_bytes().to_vec()
+        self.encapsulation_key.clone()
     }
 
     #[wasm_bindgen(getter, js_name = "decapsulationKey")]
     pub fn decapsulation_key(&self) -> Vec<u8> {
-        self.decapsulation_key.as_bytes().to_vec()
+        self.decapsulation_key.to_vec()
+    }
+
+    /// Wipes the decapsulation key, and the encapsulation key for consistency, from memory. `MlKem`
+    /// tends to be held onto across `encapsulate`/`decapsulate` calls, so callers should call this
+    /// explicitly once done rather than rely on it being dropped promptly.
+    #[wasm_bindgen]
+    pub fn zeroize(&mut self) {
+        self.decapsulation_key.zeroize();
+        self.encapsulation_key.zeroize();
+    }
+
+    #[wasm_bindgen(js_name = "toSpkiDer")]
+    pub fn to_spki_der(&self) -> Vec<u8> {
+        der::wrap_spki(ML_KEM_1024_OID, &self.encapsulation_key())
+    }
+
+    #[wasm_bindgen(js_name = "toSpkiPem")]
+    pub fn to_spki_pem(&self) -> String {
+        der::der_to_pem(&self.to_spki_der(), "PUBLIC KEY")
+    }
+
+    #[wasm_bindgen(js_name = "toPkcs8Der")]
+    pub fn to_pkcs8_der(&self) -> Vec<u8> {
+        der::wrap_pkcs8(ML_KEM_1024_OID, &self.decapsulation_key())
+    }
+
+    #[wasm_bindgen(js_name = "toPkcs8Pem")]
+    pub fn to_pkcs8_pem(&self) -> String {
+        der::der_to_pem(&self.to_pkcs8_der(), "PRIVATE KEY")
+    }
+
+    #[wasm_bindgen(js_name = "fromSpkiDer")]
+    pub fn from_spki_der(der: &[u8]) -> Result<Vec<u8>, String> {
+        der::unwrap_spki(ML_KEM_1024_OID, der)
+    }
+
+    #[wasm_bindgen(js_name = "fromSpkiPem")]
+    pub fn from_spki_pem(pem: &str) -> Result<Vec<u8>, String> {
+        der::unwrap_spki(ML_KEM_1024_OID, &der::pem_to_der(pem, "PUBLIC KEY")?)
+    }
+
+    #[wasm_bindgen(js_name = "fromPkcs8Der")]
+    pub fn from_pkcs8_der(der: &[u8]) -> Result<Vec<u8>, String> {
+        der::unwrap_pkcs8(ML_KEM_1024_OID, der)
+    }
+
+    #[wasm_bindgen(js_name = "fromPkcs8Pem")]
+    pub fn from_pkcs8_pem(pem: &str) -> Result<Vec<u8>, String> {
+        der::unwrap_pkcs8(ML_KEM_1024_OID, &der::pem_to_der(pem, "PRIVATE KEY")?)
     }
 
     #[wasm_bindgen]
@@ -90,6 +142,29 @@ impl MlKem {
         })
     }
 
+    #[wasm_bindgen]
+    pub fn encapsulate_deterministic(encapsulation_key: &[u8], m: &[u8]) -> Result<MlKemEncapsulation, String> {
+        if m.len() != 32 {
+            return Err("The encapsulation randomness 'm' is expected to be exactly 32 bytes".into());
+        }
+
+        let encoded_enc_key =
+            Encoded::<EncapsulationKey<MlKem1024Params>>::try_from(encapsulation_key)
+                .map_err(|e| format!("Could not get encoded encapsulation key from bytes: {e}"))?;
+
+        let enc_key = EncapsulationKey::<MlKem1024Params>::from_bytes(&encoded_enc_key);
+
+        let m = ml_kem::B32::try_from(m).map_err(|e| format!("Could not build 'm' from encapsulation randomness: {e}"))?;
+        let (ciphertext, shared_secret) = enc_key
+            .encapsulate_deterministic(&m)
+            .map_err(|e| format!("Could not encapsulate: {e:?}"))?;
+
+        Ok(MlKemEncapsulation {
+            ciphertext: ciphertext.to_vec(),
+            shared_secret: shared_secret.to_vec(),
+        })
+    }
+
     #[wasm_bindgen]
     pub fn decapsulate(decapsulation_key: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, String> {
         let encoded_dec_key =
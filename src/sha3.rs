@@ -1,4 +1,7 @@
-use sha3::{Digest, Sha3_512 as sha3_512};
+use sha3::{
+    Digest, Sha3_512 as sha3_512,
+    digest::{ExtendableOutput, Update, XofReader},
+};
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -18,7 +21,110 @@ impl Sha3_512 {
     #[wasm_bindgen(js_name = "sha3Hash512")]
     pub fn hash(data: &[u8]) -> Vec<u8> {
         let mut hasher = sha3_512::new();
-        hasher.update(data);
+        Digest::update(&mut hasher, data);
         hasher.finalize().to_vec()
     }
 }
+
+/// Selects which SHA-3 variant a `Sha3Hasher` computes.
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum Sha3Variant {
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+}
+
+#[derive(Clone)]
+enum HasherState {
+    Sha3_256(sha3::Sha3_256),
+    Sha3_384(sha3::Sha3_384),
+    Sha3_512(sha3::Sha3_512),
+}
+
+/// A stateful SHA-3 hasher that can be fed input incrementally across multiple WASM calls,
+/// instead of requiring the whole message to be buffered and passed to `sha3Hash512` at once.
+///
+/// # References
+///
+/// * [NIST FIPS 202: SHA-3 Standard](https://csrc.nist.gov/pubs/fips/202/final)
+#[wasm_bindgen]
+pub struct Sha3Hasher {
+    state: HasherState,
+}
+
+#[wasm_bindgen]
+impl Sha3Hasher {
+    #[wasm_bindgen(constructor)]
+    pub fn new(variant: Sha3Variant) -> Self {
+        let state = match variant {
+            Sha3Variant::Sha3_256 => HasherState::Sha3_256(sha3::Sha3_256::new()),
+            Sha3Variant::Sha3_384 => HasherState::Sha3_384(sha3::Sha3_384::new()),
+            Sha3Variant::Sha3_512 => HasherState::Sha3_512(sha3::Sha3_512::new()),
+        };
+
+        Self { state }
+    }
+
+    /// Feeds a chunk of input into the hasher. Can be called any number of times before `finalize`.
+    #[wasm_bindgen]
+    pub fn update(&mut self, chunk: &[u8]) {
+        match &mut self.state {
+            HasherState::Sha3_256(hasher) => Digest::update(hasher, chunk),
+            HasherState::Sha3_384(hasher) => Digest::update(hasher, chunk),
+            HasherState::Sha3_512(hasher) => Digest::update(hasher, chunk),
+        }
+    }
+
+    /// Computes the digest of everything fed so far. The hasher is left usable afterwards, so
+    /// `update`/`finalize` may be interleaved if the caller needs intermediate digests.
+    #[wasm_bindgen]
+    pub fn finalize(&self) -> Vec<u8> {
+        match self.state.clone() {
+            HasherState::Sha3_256(hasher) => hasher.finalize().to_vec(),
+            HasherState::Sha3_384(hasher) => hasher.finalize().to_vec(),
+            HasherState::Sha3_512(hasher) => hasher.finalize().to_vec(),
+        }
+    }
+}
+
+/// A SHAKE256 extendable-output function (XOF): a SHA-3 variant that can squeeze out a digest of
+/// any caller-chosen length, rather than a fixed 64 bytes. Directly useful for KDF-style expansion
+/// of an ML-KEM shared secret into keys of arbitrary length.
+///
+/// # References
+///
+/// * [NIST FIPS 202: SHA-3 Standard](https://csrc.nist.gov/pubs/fips/202/final)
+#[wasm_bindgen]
+pub struct Shake256 {
+    hasher: sha3::Shake256,
+}
+
+#[wasm_bindgen]
+impl Shake256 {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self { hasher: sha3::Shake256::default() }
+    }
+
+    /// Feeds a chunk of input into the XOF. Can be called any number of times before `squeeze`.
+    #[wasm_bindgen]
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.hasher.update(chunk);
+    }
+
+    /// Squeezes `outputLen` bytes of output out of everything fed so far.
+    #[wasm_bindgen(js_name = "squeeze")]
+    pub fn squeeze(&self, #[wasm_bindgen(js_name = "outputLen")] output_len: usize) -> Vec<u8> {
+        let mut reader = self.hasher.clone().finalize_xof();
+        let mut out = vec![0u8; output_len];
+        reader.read(&mut out);
+        out
+    }
+}
+
+impl Default for Shake256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}